@@ -11,11 +11,42 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 enum ExportDiagnostic {
-    #[error("eslint-plugin-import(export): Multiple exports of name '{1}'.")]
+    #[error("eslint-plugin-import(export): Multiple exports of name '{2}'.")]
     #[diagnostic(severity(warning))]
-    NamedExport(#[label] Span, Atom),
+    NamedExport(
+        #[label("Exported here")] Span,
+        #[label("Also exported here")] Span,
+        Atom,
+    ),
+    #[error(
+        "eslint-plugin-import(export): Ambiguous export for name '{1}', guaranteed by more than one star export."
+    )]
+    #[diagnostic(severity(warning))]
+    AmbiguousExport(#[label(collection, "could come from here")] Vec<Span>, Atom),
+    #[error("eslint-plugin-import(export): Multiple default exports.")]
+    #[diagnostic(severity(warning))]
+    DefaultExport(#[label(collection, "exported default here")] Vec<Span>),
 }
 
+// NOTE: distinguishing value exports from `export type` exports needs the
+// module's export kind threaded out of `oxc_semantic::ModuleRecord`, which
+// isn't available here, so this rule still dedups by name alone (a value
+// `foo` and a type `foo` are treated as the same export and will conflict).
+//
+// NOTE: recognizing CJS `module.exports`/`exports.foo = ...` assignments as
+// exports would need `oxc_semantic::ModuleRecord` to record them in the first
+// place, which it doesn't yet, so a `export foo` colliding with a re-exported
+// CJS `exports.foo` is not detected here.
+//
+// NOTE: `export { default } from "./x"` is, per the ECMAScript module-record
+// model, an indirect export entry (it carries a `[[ModuleRequest]]`) rather
+// than a local one, so there's no reason to expect it lands in
+// `exported_bindings`, which holds this module's own local export entries.
+// Detecting a local `export default` colliding with a re-exported default
+// from elsewhere would need visibility into indirect export entries that
+// isn't available here, so only two local `export default` declarations
+// would collide — which is already a parse-time SyntaxError and unreachable.
+
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/export.md>
 #[derive(Debug, Default, Clone)]
 pub struct Export;
@@ -37,8 +68,23 @@ declare_oxc_lint!(
 impl Rule for Export {
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
-        let named_export = &module_record.exported_bindings;
+        let mut local_exports = FxHashMap::default();
+        for (name, span) in &module_record.exported_bindings {
+            local_exports.insert(name.clone(), *span);
+        }
+        let mut default_export_spans = Vec::new();
+        default_export_spans.extend(module_record.export_default);
+        if default_export_spans.len() > 1 {
+            ctx.diagnostic(ExportDiagnostic::DefaultExport(default_export_spans));
+        }
+
         let mut duplicated_named_export = FxHashMap::default();
+        // Per name, the `export * from "..."` entries (by module request span) that
+        // transitively provide it, so a name exported by two or more distinct star
+        // sources with no local disambiguation can be flagged as ambiguous. Keyed by
+        // plain name, matching `local_exports`'s keying, so a name is only ever
+        // compared against itself.
+        let mut star_sources: FxHashMap<Atom, Vec<Span>> = FxHashMap::default();
         if module_record.star_export_entries.is_empty() {
             return;
         }
@@ -53,25 +99,58 @@ impl Rule for Export {
             };
 
             let remote_module_record = remote_module_record_ref.value();
-            let mut all_export_names = FxHashSet::default();
-            collect_exported_recursive(&mut all_export_names, remote_module_record);
-            for name in &all_export_names {
-                if let Some(span) = named_export.get(name) {
-                    duplicated_named_export.entry(*span).or_insert_with(|| name.clone());
+            let mut all_exports = FxHashMap::default();
+            let mut visited = FxHashSet::default();
+            visited.insert(module_identity(remote_module_record));
+            collect_exported_recursive(&mut all_exports, remote_module_record, &mut visited);
+            for (name, remote_span) in &all_exports {
+                if let Some(span) = local_exports.get(name) {
+                    duplicated_named_export
+                        .entry(*span)
+                        .or_insert_with(|| (*remote_span, name.clone()));
                 }
             }
+
+            for name in all_exports.keys() {
+                star_sources.entry(name.clone()).or_default().push(module_request.span());
+            }
         }
 
-        for (span, name) in duplicated_named_export {
-            ctx.diagnostic(ExportDiagnostic::NamedExport(span, name));
+        for (span, (other_span, name)) in duplicated_named_export {
+            ctx.diagnostic(ExportDiagnostic::NamedExport(span, other_span, name));
+        }
+
+        for (name, sources) in star_sources {
+            if sources.len() > 1 && !local_exports.contains_key(&name) {
+                ctx.diagnostic(ExportDiagnostic::AmbiguousExport(sources, name));
+            }
         }
     }
 }
 
-// TODO: support detect cycle
-fn collect_exported_recursive(result: &mut FxHashSet<Atom>, module_record: &ModuleRecord) {
-    for name in module_record.exported_bindings.keys() {
-        result.insert(name.clone());
+// Two different relative specifiers (e.g. "./a" resolved from two different
+// directories) can refer to two different files, and the same specifier text
+// can also be reused for unrelated modules elsewhere in the tree, so the
+// specifier string is not a safe proxy for "have we been here before" — it
+// can alias unrelated modules and cause real, unvisited exports to be
+// silently dropped. Key `visited` on the loaded `ModuleRecord`'s own address
+// instead, which uniquely identifies the module regardless of how it was
+// requested.
+fn module_identity(module_record: &ModuleRecord) -> usize {
+    std::ptr::from_ref(module_record) as usize
+}
+
+// `visited` is keyed by module identity (see `module_identity`), so a module
+// already on the current path is never re-entered. This bounds the traversal
+// to O(modules) even when the re-export graph is cyclic (e.g.
+// `a.js: export * from "./b"` / `b.js: export * from "./a"`).
+fn collect_exported_recursive(
+    result: &mut FxHashMap<Atom, Span>,
+    module_record: &ModuleRecord,
+    visited: &mut FxHashSet<usize>,
+) {
+    for (name, span) in &module_record.exported_bindings {
+        result.entry(name.clone()).or_insert(*span);
     }
     for export_entry in &module_record.star_export_entries {
         let Some(module_request) = &export_entry.module_request else {
@@ -82,7 +161,11 @@ fn collect_exported_recursive(result: &mut FxHashSet<Atom>, module_record: &Modu
         else {
             continue;
         };
-        collect_exported_recursive(result, remote_module_record_ref.value());
+        let remote_module_record = remote_module_record_ref.value();
+        if !visited.insert(module_identity(remote_module_record)) {
+            continue;
+        }
+        collect_exported_recursive(result, remote_module_record, visited);
     }
 }
 
@@ -91,9 +174,23 @@ fn test() {
     use crate::tester::Tester;
     use serde_json::Value;
 
-    let pass: Vec<(&str, Option<Value>)> = vec![(r#"var foo = "foo"; export default foo;"#, None)];
+    let pass: Vec<(&str, Option<Value>)> = vec![
+        (r#"var foo = "foo"; export default foo;"#, None),
+        // `cycle-a` and `cycle-b` star-export each other, and neither locally
+        // re-declares `foo`; this must terminate instead of recursing forever,
+        // and must not flag anything since there's no local collision.
+        (r#"export * from "./cycle-a""#, None),
+    ];
 
-    let fail = vec![(r#"let foo; export { foo }; export * from "./export-all""#, None)];
+    let fail = vec![
+        (r#"let foo; export { foo }; export * from "./export-all""#, None),
+        (r#"export * from "./export-all"; export * from "./export-all""#, None),
+        // `cycle-b` (reached transitively through `cycle-a`) exports `foo`,
+        // which collides with the local `foo` below. This only fires if the
+        // cyclic star-export graph is actually walked to completion rather
+        // than being skipped as "already visited" or hanging.
+        (r#"let foo; export { foo }; export * from "./cycle-a""#, None),
+    ];
 
     Tester::new(Export::NAME, pass, fail)
         .change_rule_path("index.js")